@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::env;
+
+use rocket::outcome::Outcome;
+use rocket::request::{self, FromRequest, Request};
+
+use crate::error::ApiError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    Read,
+    Write,
+}
+
+/// An authenticated caller, carrying the scope their key was granted.
+pub struct ApiKey {
+    pub scope: Scope,
+}
+
+impl ApiKey {
+    pub fn require_write(&self) -> Result<(), ApiError> {
+        if self.scope == Scope::Write {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden(
+                "this key is not authorized for write access".to_string(),
+            ))
+        }
+    }
+}
+
+/// `API_KEYS` holds entries of the form `token` or `token:scope` (scope defaults to `read`),
+/// comma-separated, e.g. `API_KEYS=abc123:write,def456:read`.
+fn configured_keys() -> HashMap<String, Scope> {
+    let mut keys = HashMap::new();
+    if let Ok(raw) = env::var("API_KEYS") {
+        for entry in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (token, scope) = match entry.split_once(':') {
+                Some((token, "write")) => (token, Scope::Write),
+                Some((token, _)) => (token, Scope::Read),
+                None => (entry, Scope::Read),
+            };
+            keys.insert(token.to_string(), scope);
+        }
+    }
+    keys
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ApiKey {
+    type Error = ApiError;
+
+    async fn from_request(request: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let header = request.headers().get_one("Authorization");
+
+        let token = match header.and_then(|h| h.strip_prefix("Bearer ")) {
+            Some(token) => token,
+            None => {
+                return Outcome::Error((
+                    rocket::http::Status::Unauthorized,
+                    ApiError::Unauthorized("missing Authorization: Bearer <token> header".to_string()),
+                ))
+            }
+        };
+
+        match configured_keys().get(token) {
+            Some(&scope) => Outcome::Success(ApiKey { scope }),
+            None => Outcome::Error((
+                rocket::http::Status::Unauthorized,
+                ApiError::Unauthorized("invalid API key".to_string()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use rocket::catch;
+use rocket::http::Status;
+use rocket::request::Request;
+use rocket::response::{self, Responder};
+use rocket::serde::json::{json, Json, Value};
+
+/// Uniform error type for API handlers, serialized as `{"error": ..., "status": ...}`.
+#[derive(Debug)]
+pub enum ApiError {
+    BadRequest(String),
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound,
+    Storage(String),
+}
+
+impl ApiError {
+    fn status(&self) -> Status {
+        match self {
+            ApiError::BadRequest(_) => Status::BadRequest,
+            ApiError::Unauthorized(_) => Status::Unauthorized,
+            ApiError::Forbidden(_) => Status::Forbidden,
+            ApiError::NotFound => Status::NotFound,
+            ApiError::Storage(_) => Status::InternalServerError,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self {
+            ApiError::BadRequest(msg) => msg.clone(),
+            ApiError::Unauthorized(msg) => msg.clone(),
+            ApiError::Forbidden(msg) => msg.clone(),
+            ApiError::NotFound => "reading not found".to_string(),
+            ApiError::Storage(msg) => msg.clone(),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for ApiError {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
+        let body = json!({
+            "error": self.message(),
+            "status": status.code,
+        });
+
+        response::Response::build_from(Json(body).respond_to(request)?)
+            .status(status)
+            .ok()
+    }
+}
+
+/// Catches rejections that happen before a handler runs (e.g. a failed `ApiKey` request
+/// guard), rendering them in the same `{"error": ..., "status": ...}` shape as `ApiError`.
+#[catch(default)]
+pub fn default_catcher(status: Status, _request: &Request) -> Json<Value> {
+    Json(json!({
+        "error": status.reason().unwrap_or("error").to_string(),
+        "status": status.code,
+    }))
+}
@@ -1,3 +1,10 @@
+mod auth;
+mod deck;
+mod error;
+mod query;
+mod signing;
+mod storage;
+
 use chrono::{DateTime, Utc};
 use rocket::fairing::AdHoc;
 use rocket::response::status;
@@ -5,14 +12,19 @@ use rocket::serde::json::{json, Json, Value};
 use rocket::serde::{Deserialize, Serialize};
 use rocket::{get, launch, post, routes, State};
 use rocket_cors::{AllowedOrigins, CorsOptions};
-use std::collections::HashMap;
 use std::env;
-use std::sync::RwLock;
 use uuid::Uuid;
 
+use auth::ApiKey;
+use deck::{DrawnCard, Spread};
+use error::ApiError;
+use query::{PaginatedReadings, ReadingQuery};
+use signing::Signed;
+use storage::{SqliteStorage, Storage};
+
 // Data structures
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct TarotReading {
+pub struct TarotReading {
     id: Uuid,
     question: String,
     cards: Vec<String>,
@@ -27,14 +39,29 @@ struct CreateReadingRequest {
     interpretation: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct DrawReadingRequest {
+    question: String,
+    spread: Spread,
+    seed: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+struct DrawnReading {
+    id: Uuid,
+    question: String,
+    spread: Spread,
+    cards: Vec<DrawnCard>,
+    created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct HealthResponse {
     status: String,
     timestamp: DateTime<Utc>,
 }
 
-// In-memory storage
-type ReadingsStore = RwLock<HashMap<Uuid, TarotReading>>;
+type ReadingsStore = Box<dyn Storage>;
 
 // Route handlers
 #[get("/")]
@@ -46,7 +73,8 @@ fn index() -> Value {
             "health": "GET /health",
             "readings": "GET /api/readings",
             "reading": "GET /api/readings/{id}",
-            "create_reading": "POST /api/readings"
+            "create_reading": "POST /api/readings",
+            "draw_reading": "POST /api/readings/draw"
         }
     })
 }
@@ -59,26 +87,34 @@ fn health_check() -> Json<HealthResponse> {
     })
 }
 
-#[get("/api/readings")]
-fn get_readings(store: &State<ReadingsStore>) -> Json<Vec<TarotReading>> {
-    let readings = store.read().unwrap();
-    let mut readings_list: Vec<TarotReading> = readings.values().cloned().collect();
-    readings_list.sort_by(|a, b| b.created_at.cmp(&a.created_at));
-    Json(readings_list)
+#[get("/api/readings?<query..>")]
+async fn get_readings(
+    store: &State<ReadingsStore>,
+    query: ReadingQuery,
+) -> Result<Json<PaginatedReadings>, ApiError> {
+    let readings_list = store.list().await.map_err(ApiError::Storage)?;
+    Ok(Json(query.apply(readings_list)))
 }
 
 #[get("/api/readings/<id>")]
-fn get_reading(store: &State<ReadingsStore>, id: String) -> Option<Json<TarotReading>> {
-    let uuid = Uuid::parse_str(&id).ok()?;
-    let readings = store.read().unwrap();
-    readings.get(&uuid).cloned().map(Json)
+async fn get_reading(
+    store: &State<ReadingsStore>,
+    id: String,
+) -> Result<Json<TarotReading>, ApiError> {
+    let uuid =
+        Uuid::parse_str(&id).map_err(|_| ApiError::BadRequest(format!("invalid reading id: {id}")))?;
+    let reading = store.get(uuid).await.map_err(ApiError::Storage)?;
+    reading.map(Json).ok_or(ApiError::NotFound)
 }
 
 #[post("/api/readings", data = "<request>")]
-fn create_reading(
+async fn create_reading(
     store: &State<ReadingsStore>,
-    request: Json<CreateReadingRequest>,
-) -> status::Created<Json<TarotReading>> {
+    api_key: ApiKey,
+    request: Signed<CreateReadingRequest>,
+) -> Result<status::Created<Json<TarotReading>>, ApiError> {
+    api_key.require_write()?;
+
     let id = Uuid::new_v4();
     let now = Utc::now();
 
@@ -90,11 +126,50 @@ fn create_reading(
         created_at: now,
     };
 
-    let mut readings = store.write().unwrap();
-    readings.insert(id, reading.clone());
+    store.insert(&reading).await.map_err(ApiError::Storage)?;
+
+    let location = format!("/api/readings/{}", id);
+    Ok(status::Created::new(location).body(Json(reading)))
+}
+
+#[post("/api/readings/draw", data = "<request>")]
+async fn draw_reading(
+    store: &State<ReadingsStore>,
+    api_key: ApiKey,
+    request: Signed<DrawReadingRequest>,
+) -> Result<status::Created<Json<DrawnReading>>, ApiError> {
+    api_key.require_write()?;
+
+    let drawn_cards = deck::draw(request.spread, request.seed);
+    let id = Uuid::new_v4();
+    let now = Utc::now();
+
+    let cards = drawn_cards.iter().map(|d| d.card.name.clone()).collect();
+    let interpretation = drawn_cards
+        .iter()
+        .map(|d| format!("{}: {} ({:?})", d.position, d.card.name, d.orientation))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    let reading = TarotReading {
+        id,
+        question: request.question.clone(),
+        cards,
+        interpretation,
+        created_at: now,
+    };
+    store.insert(&reading).await.map_err(ApiError::Storage)?;
+
+    let drawn = DrawnReading {
+        id,
+        question: request.question.clone(),
+        spread: request.spread,
+        cards: drawn_cards,
+        created_at: now,
+    };
 
     let location = format!("/api/readings/{}", id);
-    status::Created::new(location).body(Json(reading))
+    Ok(status::Created::new(location).body(Json(drawn)))
 }
 
 // Configuration
@@ -130,9 +205,7 @@ fn configure_cors() -> CorsOptions {
     }
 }
 
-fn initialize_sample_data() -> ReadingsStore {
-    let mut store = HashMap::new();
-
+async fn seed_sample_data(store: &dyn Storage) -> Result<(), String> {
     // Add sample readings
     let samples = vec![
         (
@@ -159,33 +232,44 @@ fn initialize_sample_data() -> ReadingsStore {
     ];
 
     for (id, question, cards, interpretation, created_at) in samples {
-        store.insert(
+        let reading = TarotReading {
             id,
-            TarotReading {
-                id,
-                question: question.to_string(),
-                cards,
-                interpretation: interpretation.to_string(),
-                created_at,
-            },
-        );
+            question: question.to_string(),
+            cards,
+            interpretation: interpretation.to_string(),
+            created_at,
+        };
+        store.insert(&reading).await?;
     }
 
-    RwLock::new(store)
+    Ok(())
 }
 
 #[launch]
-fn rocket() -> _ {
+async fn rocket() -> _ {
     // Initialize logger
     env_logger::init_from_env(env_logger::Env::default().default_filter_or("info"));
 
     // Load .env file if it exists (for local development)
     dotenv::dotenv().ok();
 
-    log::info!("Starting Tarot Reader API server (no database)");
+    let database_url =
+        env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite://tarot.db?mode=rwc".to_string());
+
+    log::info!("Starting Tarot Reader API server (database: {})", database_url);
+
+    let sqlite_store = SqliteStorage::connect(&database_url)
+        .await
+        .expect("Failed to connect to database and run migrations");
+
+    if sqlite_store.is_empty().await.unwrap_or(false) {
+        log::info!("Database is empty, seeding sample data");
+        seed_sample_data(&sqlite_store)
+            .await
+            .expect("Failed to seed sample data");
+    }
 
-    // Initialize in-memory storage with sample data
-    let readings_store = initialize_sample_data();
+    let readings_store: ReadingsStore = Box::new(sqlite_store);
 
     // Configure CORS
     let cors = configure_cors()
@@ -201,6 +285,7 @@ fn rocket() -> _ {
                 res.set_raw_header("X-API-Version", "1.0.0");
             })
         }))
+        .register("/", rocket::catchers![error::default_catcher])
         .mount(
             "/",
             routes![
@@ -209,6 +294,7 @@ fn rocket() -> _ {
                 get_readings,
                 get_reading,
                 create_reading,
+                draw_reading,
             ],
         )
 }
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use rocket::form::FromForm;
+use rocket::serde::Serialize;
+
+use crate::TarotReading;
+
+#[derive(Debug, FromForm)]
+pub struct ReadingQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub sort: Option<SortField>,
+    pub order: Option<SortOrder>,
+    pub card: Option<String>,
+    pub since: Option<Rfc3339DateTime>,
+    pub until: Option<Rfc3339DateTime>,
+}
+
+/// `chrono::DateTime<Utc>` has no built-in `FromFormField` impl, so `since`/`until` are
+/// parsed through this wrapper using the same RFC 3339 parsing `storage.rs` uses for rows.
+#[derive(Debug, Clone, Copy)]
+pub struct Rfc3339DateTime(pub DateTime<Utc>);
+
+#[derive(Debug, Clone, Copy)]
+pub enum SortField {
+    CreatedAt,
+    Question,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl<'v> rocket::form::FromFormField<'v> for SortField {
+    fn from_value(field: rocket::form::ValueField<'v>) -> rocket::form::Result<'v, Self> {
+        match field.value {
+            "created_at" => Ok(SortField::CreatedAt),
+            "question" => Ok(SortField::Question),
+            _ => Err(rocket::form::Error::validation("expected created_at or question").into()),
+        }
+    }
+}
+
+impl<'v> rocket::form::FromFormField<'v> for SortOrder {
+    fn from_value(field: rocket::form::ValueField<'v>) -> rocket::form::Result<'v, Self> {
+        match field.value {
+            "asc" => Ok(SortOrder::Asc),
+            "desc" => Ok(SortOrder::Desc),
+            _ => Err(rocket::form::Error::validation("expected asc or desc").into()),
+        }
+    }
+}
+
+impl<'v> rocket::form::FromFormField<'v> for Rfc3339DateTime {
+    fn from_value(field: rocket::form::ValueField<'v>) -> rocket::form::Result<'v, Self> {
+        DateTime::parse_from_rfc3339(field.value)
+            .map(|dt| Rfc3339DateTime(dt.with_timezone(&Utc)))
+            .map_err(|_| rocket::form::Error::validation("expected an RFC 3339 timestamp").into())
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PaginatedReadings {
+    pub items: Vec<TarotReading>,
+    pub total: usize,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+impl ReadingQuery {
+    /// Applies card/date filtering, sorting, then limit/offset pagination to the full list.
+    pub fn apply(&self, mut readings: Vec<TarotReading>) -> PaginatedReadings {
+        if let Some(card) = &self.card {
+            let card = card.to_lowercase();
+            readings.retain(|r| r.cards.iter().any(|c| c.to_lowercase() == card));
+        }
+        if let Some(since) = self.since {
+            readings.retain(|r| r.created_at >= since.0);
+        }
+        if let Some(until) = self.until {
+            readings.retain(|r| r.created_at <= until.0);
+        }
+
+        let sort = self.sort.unwrap_or(SortField::CreatedAt);
+        let order = self.order.unwrap_or(SortOrder::Desc);
+        readings.sort_by(|a, b| {
+            let cmp = match sort {
+                SortField::CreatedAt => a.created_at.cmp(&b.created_at),
+                SortField::Question => a.question.cmp(&b.question),
+            };
+            match order {
+                SortOrder::Asc => cmp,
+                SortOrder::Desc => cmp.reverse(),
+            }
+        });
+
+        let total = readings.len();
+        let offset = self.offset.unwrap_or(0);
+        let limit = self.limit.unwrap_or(total);
+
+        let items = readings.into_iter().skip(offset).take(limit).collect();
+
+        PaginatedReadings {
+            items,
+            total,
+            limit,
+            offset,
+        }
+    }
+}
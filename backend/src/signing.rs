@@ -0,0 +1,169 @@
+use std::env;
+use std::ops::Deref;
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use rocket::data::{self, Data, FromData, ToByteUnit};
+use rocket::http::Status;
+use rocket::request::Request;
+use rocket::serde::de::DeserializeOwned;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::error::ApiError;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Requests older or newer than this many seconds (relative to `X-Timestamp`) are rejected
+/// as replays, unless overridden via `SIGNATURE_SKEW_SECONDS`.
+const DEFAULT_SKEW_SECONDS: i64 = 300;
+
+const MAX_BODY_SIZE: u32 = 1024 * 1024;
+
+/// Wraps a JSON body that must carry a valid `X-Signature`/`X-Timestamp` pair whenever
+/// `SIGNING_SECRET` is configured. When the secret is unset, verification is skipped and
+/// the body is parsed as plain JSON.
+pub struct Signed<T>(pub T);
+
+impl<T> Deref for Signed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+fn skew_window() -> i64 {
+    env::var("SIGNATURE_SKEW_SECONDS")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SKEW_SECONDS)
+}
+
+fn verify(secret: &str, timestamp: &str, body: &[u8], signature: &str) -> Result<(), ApiError> {
+    let age = Utc::now().timestamp() - timestamp.parse::<i64>().map_err(|_| {
+        ApiError::Unauthorized("X-Timestamp must be a unix timestamp".to_string())
+    })?;
+    if age.abs() > skew_window() {
+        return Err(ApiError::Unauthorized(
+            "request timestamp is outside the allowed window".to_string(),
+        ));
+    }
+
+    let hex_signature = signature
+        .strip_prefix("sha256=")
+        .ok_or_else(|| ApiError::Unauthorized("X-Signature must be in sha256=<hex> form".to_string()))?;
+    let expected_bytes = hex::decode(hex_signature)
+        .map_err(|_| ApiError::Unauthorized("X-Signature is not valid hex".to_string()))?;
+
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    let computed = mac.finalize().into_bytes();
+
+    if computed.ct_eq(&expected_bytes).into() {
+        Ok(())
+    } else {
+        Err(ApiError::Unauthorized("signature mismatch".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &str, timestamp: &str, body: &[u8]) -> String {
+        let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(body);
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn verify_accepts_a_correctly_signed_body() {
+        let secret = "shh";
+        let body = br#"{"question":"test"}"#;
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign(secret, &timestamp, body);
+
+        assert!(verify(secret, &timestamp, body, &signature).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_a_stale_timestamp() {
+        let secret = "shh";
+        let body = br#"{"question":"test"}"#;
+        let timestamp = (Utc::now().timestamp() - DEFAULT_SKEW_SECONDS - 1).to_string();
+        let signature = sign(secret, &timestamp, body);
+
+        assert!(verify(secret, &timestamp, body, &signature).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_a_mismatched_signature() {
+        let secret = "shh";
+        let body = br#"{"question":"test"}"#;
+        let timestamp = Utc::now().timestamp().to_string();
+        let signature = sign("wrong-secret", &timestamp, body);
+
+        assert!(verify(secret, &timestamp, body, &signature).is_err());
+    }
+
+    #[test]
+    fn verify_rejects_a_signature_of_the_wrong_length() {
+        let secret = "shh";
+        let body = br#"{"question":"test"}"#;
+        let timestamp = Utc::now().timestamp().to_string();
+
+        assert!(verify(secret, &timestamp, body, "sha256=abcd").is_err());
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for Signed<T> {
+    type Error = ApiError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let body = match data.open(MAX_BODY_SIZE.bytes()).into_bytes().await {
+            Ok(body) => body.into_inner(),
+            Err(e) => {
+                return data::Outcome::Error((
+                    Status::BadRequest,
+                    ApiError::BadRequest(format!("failed to read request body: {e}")),
+                ))
+            }
+        };
+
+        if let Ok(secret) = env::var("SIGNING_SECRET") {
+            let signature = req.headers().get_one("X-Signature");
+            let timestamp = req.headers().get_one("X-Timestamp");
+
+            let (signature, timestamp) = match (signature, timestamp) {
+                (Some(sig), Some(ts)) => (sig, ts),
+                _ => {
+                    return data::Outcome::Error((
+                        Status::Unauthorized,
+                        ApiError::Unauthorized(
+                            "X-Signature and X-Timestamp headers are required".to_string(),
+                        ),
+                    ))
+                }
+            };
+
+            if let Err(e) = verify(&secret, timestamp, &body, signature) {
+                return data::Outcome::Error((Status::Unauthorized, e));
+            }
+        }
+
+        match serde_json::from_slice(&body) {
+            Ok(value) => data::Outcome::Success(Signed(value)),
+            Err(e) => data::Outcome::Error((
+                Status::BadRequest,
+                ApiError::BadRequest(format!("invalid JSON body: {e}")),
+            )),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::TarotReading;
+
+/// Persistence boundary for readings. Route handlers talk to this trait so the
+/// backing engine (SQLite today, maybe Postgres later) stays swappable.
+#[async_trait]
+pub trait Storage: Send + Sync {
+    async fn list(&self) -> Result<Vec<TarotReading>, String>;
+    async fn get(&self, id: Uuid) -> Result<Option<TarotReading>, String>;
+    async fn insert(&self, reading: &TarotReading) -> Result<(), String>;
+}
+
+pub struct SqliteStorage {
+    pool: SqlitePool,
+}
+
+impl SqliteStorage {
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await?;
+        sqlx::migrate!("./migrations").run(&pool).await?;
+        Ok(Self { pool })
+    }
+
+    pub async fn is_empty(&self) -> Result<bool, sqlx::Error> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM readings")
+            .fetch_one(&self.pool)
+            .await?;
+        let count: i64 = row.try_get("count")?;
+        Ok(count == 0)
+    }
+}
+
+fn row_to_reading(row: SqliteRow) -> Result<TarotReading, String> {
+    let id: String = row.try_get("id").map_err(|e| e.to_string())?;
+    let cards: String = row.try_get("cards").map_err(|e| e.to_string())?;
+    let created_at: String = row.try_get("created_at").map_err(|e| e.to_string())?;
+
+    Ok(TarotReading {
+        id: Uuid::parse_str(&id).map_err(|e| e.to_string())?,
+        question: row.try_get("question").map_err(|e| e.to_string())?,
+        cards: serde_json::from_str(&cards).map_err(|e| e.to_string())?,
+        interpretation: row.try_get("interpretation").map_err(|e| e.to_string())?,
+        created_at: DateTime::parse_from_rfc3339(&created_at)
+            .map_err(|e| e.to_string())?
+            .with_timezone(&Utc),
+    })
+}
+
+#[async_trait]
+impl Storage for SqliteStorage {
+    async fn list(&self) -> Result<Vec<TarotReading>, String> {
+        let rows = sqlx::query("SELECT id, question, cards, interpretation, created_at FROM readings")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        rows.into_iter().map(row_to_reading).collect()
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<TarotReading>, String> {
+        let row = sqlx::query(
+            "SELECT id, question, cards, interpretation, created_at FROM readings WHERE id = ?",
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        row.map(row_to_reading).transpose()
+    }
+
+    async fn insert(&self, reading: &TarotReading) -> Result<(), String> {
+        let cards = serde_json::to_string(&reading.cards).map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            "INSERT INTO readings (id, question, cards, interpretation, created_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(reading.id.to_string())
+        .bind(&reading.question)
+        .bind(cards)
+        .bind(&reading.interpretation)
+        .bind(reading.created_at.to_rfc3339())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}